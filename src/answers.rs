@@ -0,0 +1,74 @@
+//! Known-good answers used by `--check` to catch regressions.
+//!
+//! Each `answers/year{year}.txt` holds one `day|part1|part2` line per solved day. The format is
+//! deliberately plain text rather than a structured format, matching the rest of the crate's
+//! avoidance of dependencies for things `str::split` already handles.
+use std::collections::HashMap;
+use std::fs::read_to_string;
+
+/// Expected `(part1, part2)` answers for every day of a single year, keyed by day.
+pub struct Answers(HashMap<u32, (String, String)>);
+
+impl Answers {
+    /// Loads `answers/year{year}.txt`, returning an empty set of answers if it doesn't exist.
+    pub fn load(year: u32) -> Self {
+        let path = ["answers", &format!("year{year}.txt")].iter().collect::<std::path::PathBuf>();
+
+        let Ok(contents) = read_to_string(path) else {
+            return Answers(HashMap::new());
+        };
+
+        let entries = contents.lines().filter_map(parse_line).collect();
+
+        Answers(entries)
+    }
+
+    /// Looks up the expected `(part1, part2)` pair for `day`, if any answer is on record.
+    pub fn get(&self, day: u32) -> Option<&(String, String)> {
+        self.0.get(&day)
+    }
+}
+
+/// Parses one `day|part1|part2` line, returning `None` for anything malformed rather than
+/// failing the whole file over it.
+fn parse_line(line: &str) -> Option<(u32, (String, String))> {
+    let mut fields = line.splitn(3, '|');
+    let day = fields.next()?.trim().parse().ok()?;
+    let part1 = fields.next()?.trim().to_string();
+    let part2 = fields.next()?.trim().to_string();
+    Some((day, (part1, part2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_well_formed_line() {
+        let (day, (part1, part2)) = parse_line("4|346386|9958218").unwrap();
+        assert_eq!(day, 4);
+        assert_eq!(part1, "346386");
+        assert_eq!(part2, "9958218");
+    }
+
+    #[test]
+    fn trims_whitespace_around_fields() {
+        let (day, (part1, part2)) = parse_line(" 4 | 346386 | 9958218 ").unwrap();
+        assert_eq!(day, 4);
+        assert_eq!(part1, "346386");
+        assert_eq!(part2, "9958218");
+    }
+
+    #[test]
+    fn rejects_lines_missing_fields() {
+        assert!(parse_line("4|346386").is_none());
+        assert!(parse_line("not a number|1|2").is_none());
+        assert!(parse_line("").is_none());
+    }
+
+    #[test]
+    fn load_returns_empty_answers_when_file_is_missing() {
+        let answers = Answers::load(999_999);
+        assert!(answers.get(1).is_none());
+    }
+}
@@ -1,16 +1,36 @@
+mod answers;
+mod fetch;
+mod pool;
+mod reporter;
+
+use answers::Answers;
 use aoc::util::ansi::*;
 use aoc::util::parse::*;
 use aoc::*;
+use pool::Computed;
+use reporter::{Check, CheckOutcome, Outcome};
+use std::collections::HashMap;
 use std::env::args;
-use std::fs::read_to_string;
 use std::iter::empty;
 use std::path::PathBuf;
+use std::process::exit;
 use std::time::Duration;
 use std::time::Instant;
 
+/// Number of untimed iterations used to warm up caches before a benchmark run starts.
+const WARMUP_ITERATIONS: u32 = 3;
+/// A benchmark stops collecting samples once this wall-clock budget is spent, even if it hasn't
+/// reached [`MAX_ITERATIONS`] yet.
+const BENCH_BUDGET: Duration = Duration::from_secs(3);
+/// Upper bound on samples collected, in case a solution is so fast the time budget never elapses.
+const MAX_ITERATIONS: u32 = 10_000;
+
 fn main() {
-    // Parse command line options
-    let (year, day) = match args().nth(1) {
+    // Parse command line options. Recognized flags are consumed first so that e.g.
+    // `aoc --format=json bench 2015 4` still finds "2015" as its first positional argument,
+    // rather than any of the flags themselves.
+    let bench = args().any(|arg| arg == "bench");
+    let (year, day) = match args().skip(1).find(|arg| !is_recognized_flag(arg)) {
         Some(arg) => {
             let str = arg.as_str();
             let mut iter = str.iter_unsigned();
@@ -18,6 +38,12 @@ fn main() {
         }
         None => (None, None),
     };
+    let check = args().any(|arg| arg == "--check");
+    let format = args().find_map(|arg| arg.strip_prefix("--format=").map(str::to_string));
+    let jobs = args()
+        .find_map(|arg| arg.strip_prefix("--jobs=").map(str::to_string))
+        .and_then(|jobs| jobs.parse().ok());
+    let mut reporter = reporter::from_arg(format.as_deref());
 
     // Filter solutions
     let solutions: Vec<_> = empty()
@@ -34,39 +60,249 @@ fn main() {
         .filter(|solution| day == Some(solution.day) || day.is_none())
         .collect();
 
-    // Pretty print output and timing for each solution
+    if bench {
+        run_bench(&solutions);
+        return;
+    }
+
+    // Independent days can be computed off the main thread; a single day (or a selection of just
+    // one solution) runs in place.
+    let parallel = day.is_none() && solutions.len() > 1;
+    let (computed, cpu_time, elapsed) =
+        if parallel { pool::run(&solutions, jobs) } else { run_sequential(&solutions) };
+
+    // Report each result via the selected Reporter, then optionally check it
+    let mut answers_by_year: HashMap<u32, Answers> = HashMap::new();
+    let mut any_mismatch = false;
+
+    for Computed { year, day, title, answers, duration } in &computed {
+        match answers {
+            Some((answer1, answer2)) => {
+                let check = check.then(|| {
+                    let answers =
+                        answers_by_year.entry(*year).or_insert_with(|| Answers::load(*year));
+                    check_answers(answer1, answer2, answers.get(*day))
+                });
+                any_mismatch |= check.is_some_and(|check| check.is_mismatch());
+
+                let outcome = Outcome::Solved { answer1, answer2, duration: *duration, check };
+                reporter.report(*year, *day, title, outcome);
+            }
+            None => reporter.report(*year, *day, title, Outcome::Missing),
+        }
+    }
+
+    if parallel {
+        reporter.timing_summary(cpu_time, elapsed);
+    }
+    reporter.finish(computed.len(), elapsed);
+
+    if check && any_mismatch {
+        exit(1);
+    }
+}
+
+/// Computes every solution one at a time on the main thread, mirroring [`pool::run`]'s
+/// return value so the run loop doesn't need to know which strategy produced its results.
+fn run_sequential(solutions: &[Solution]) -> (Vec<Computed>, Duration, Duration) {
     let mut elapsed = Duration::ZERO;
 
-    for Solution { year, day, wrapper } in &solutions {
-        let path: PathBuf =
-            ["input", &format!("year{year}"), &format!("day{day:02}.txt")].iter().collect();
-
-        if let Ok(data) = read_to_string(&path) {
-            let time = Instant::now();
-            let (answer1, answer2) = wrapper(&data);
-            let duration = time.elapsed().as_micros();
-            elapsed += time.elapsed();
-
-            println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
-            println!("    Part 1: {answer1}");
-            println!("    Part 2: {answer2}");
-            println!("    Duration: {duration} μs");
-        } else {
-            eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
+    let computed = solutions
+        .iter()
+        .map(|Solution { year, day, title, wrapper, .. }| {
+            let path = input_path(*year, *day);
+
+            let (answers, duration) = match fetch::load_input(*year, *day, &path) {
+                Some(data) => {
+                    let start = Instant::now();
+                    let answers = wrapper(&data);
+                    (Some(answers), start.elapsed())
+                }
+                None => (None, Duration::ZERO),
+            };
+            elapsed += duration;
+
+            Computed { year: *year, day: *day, title: *title, answers, duration }
+        })
+        .collect();
+
+    (computed, elapsed, elapsed)
+}
+
+/// Compares a solution's produced answers against the expected pair on record. The `Reporter`
+/// decides how to display the result, so this only classifies it rather than printing anything.
+fn check_answers(answer1: &str, answer2: &str, expected: Option<&(String, String)>) -> Check {
+    let Some((expected1, expected2)) = expected else {
+        return Check { part1: CheckOutcome::NoRecord, part2: CheckOutcome::NoRecord };
+    };
+
+    let outcome = |pass: bool| if pass { CheckOutcome::Pass } else { CheckOutcome::Fail };
+    Check { part1: outcome(answer1 == expected1), part2: outcome(answer2 == expected2) }
+}
+
+/// Expected location of a day's puzzle input on disk.
+fn input_path(year: u32, day: u32) -> PathBuf {
+    ["input", &format!("year{year}"), &format!("day{day:02}.txt")].iter().collect()
+}
+
+/// Whether `arg` is one of the flags `main` understands, as opposed to the year/day positional
+/// argument. Kept in sync with every flag parsed in `main` so a new one doesn't get mistaken for
+/// the position the next time it's added on top of this.
+fn is_recognized_flag(arg: &str) -> bool {
+    arg == "bench"
+        || arg == "--check"
+        || arg.starts_with("--format=")
+        || arg.starts_with("--jobs=")
+}
+
+/// Runs each selected [`Solution`] repeatedly and reports statistical timing, rather than the
+/// single noisy sample the default mode prints.
+///
+/// Parse and solve phases are timed separately: some solutions (e.g. `year2015::day04`) spawn
+/// their own threads inside `parse`, so lumping that cost in with every timed solve iteration
+/// would double count it. Parse is instead timed once per iteration on its own.
+fn run_bench(solutions: &[Solution]) {
+    for Solution { year, day, title, bench, .. } in solutions {
+        let path = input_path(*year, *day);
+
+        let Some(data) = fetch::load_input(*year, *day, &path) else {
+            eprintln!("{BOLD}{RED}{year} Day {day:02}: {title}{RESET}");
             eprintln!("    Missing input!");
             eprintln!("    Place input file in {BOLD}{WHITE}{}{RESET}", path.display());
+            continue;
+        };
+
+        // Warm up caches before any timed iteration.
+        for _ in 0..WARMUP_ITERATIONS {
+            bench(&data);
+        }
+
+        let mut parse_samples = Vec::new();
+        let mut solve_samples = Vec::new();
+        let start = Instant::now();
+
+        while solve_samples.len() < MAX_ITERATIONS as usize {
+            let (parse_time, solve_time, ..) = bench(&data);
+            parse_samples.push(parse_time);
+            solve_samples.push(solve_time);
+
+            if start.elapsed() >= BENCH_BUDGET {
+                break;
+            }
         }
+
+        let parse_stats = Stats::new(&mut parse_samples);
+        let solve_stats = Stats::new(&mut solve_samples);
+
+        println!("{BOLD}{YELLOW}{year} Day {day:02}: {title}{RESET}");
+        println!("    Iterations: {}", solve_stats.iterations);
+        println!("    Parse: {parse_stats}");
+        println!("    Solve: {solve_stats}");
+    }
+}
+
+/// Summary statistics over a batch of timed samples.
+struct Stats {
+    iterations: u32,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    stddev: Duration,
+}
+
+impl Stats {
+    /// Sorts `samples` in place to find the median, then derives the remaining statistics.
+    fn new(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+
+        let iterations = samples.len() as u32;
+        let min = samples[0];
+        let median = samples[samples.len() / 2];
+
+        let total: Duration = samples.iter().sum();
+        let mean = total / iterations;
+
+        let variance = samples
+            .iter()
+            .map(|&sample| {
+                let delta = sample.as_secs_f64() - mean.as_secs_f64();
+                delta * delta
+            })
+            .sum::<f64>()
+            / f64::from(iterations);
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+
+        Stats { iterations, min, median, mean, stddev }
     }
+}
 
-    // Print totals
-    println!("{BOLD}{RED}Solutions: {}{RESET}", solutions.len());
-    println!("{BOLD}{GREEN}Elapsed: {} ms{RESET}", elapsed.as_millis());
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?} median {:?} mean {:?} stddev {:?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_new_computes_min_median_mean_stddev() {
+        let mut samples = vec![
+            Duration::from_millis(30),
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(40),
+            Duration::from_millis(50),
+        ];
+
+        let stats = Stats::new(&mut samples);
+
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(stats.median, Duration::from_millis(30));
+        assert_eq!(stats.mean, Duration::from_millis(30));
+        assert!(stats.stddev > Duration::ZERO);
+    }
+
+    #[test]
+    fn stats_new_handles_identical_samples() {
+        let mut samples = vec![Duration::from_millis(5); 4];
+
+        let stats = Stats::new(&mut samples);
+
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn is_recognized_flag_matches_every_flag_parsed_in_main() {
+        assert!(is_recognized_flag("bench"));
+        assert!(is_recognized_flag("--check"));
+        assert!(is_recognized_flag("--format=json"));
+        assert!(is_recognized_flag("--jobs=4"));
+        assert!(!is_recognized_flag("2015"));
+        assert!(!is_recognized_flag("4"));
+    }
+
+    #[test]
+    fn positional_year_day_skip_flags_that_precede_them() {
+        let args = ["aoc", "--format=json", "--jobs=4", "2015", "4"];
+        let position = args.iter().skip(1).find(|arg| !is_recognized_flag(arg)).unwrap();
+        assert_eq!(*position, "2015");
+    }
 }
 
 struct Solution {
     year: u32,
     day: u32,
+    title: &'static str,
     wrapper: fn(&str) -> (String, String),
+    bench: fn(&str) -> (Duration, Duration, String, String),
 }
 
 macro_rules! solution {
@@ -74,6 +310,7 @@ macro_rules! solution {
         Solution {
             year: (&stringify!($year)).unsigned(),
             day: (&stringify!($day)).unsigned(),
+            title: $year::$day::TITLE,
             wrapper: |data: &str| {
                 use $year::$day::*;
 
@@ -83,6 +320,20 @@ macro_rules! solution {
 
                 (part1, part2)
             },
+            bench: |data: &str| {
+                use $year::$day::*;
+
+                let parse_start = Instant::now();
+                let input = parse(&data);
+                let parse_time = parse_start.elapsed();
+
+                let solve_start = Instant::now();
+                let part1 = part1(&input).to_string();
+                let part2 = part2(&input).to_string();
+                let solve_time = solve_start.elapsed();
+
+                (parse_time, solve_time, part1, part2)
+            },
         }
     };
 }
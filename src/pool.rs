@@ -0,0 +1,68 @@
+//! Thread-pool runner for computing many independent days at once.
+//!
+//! The sequential run loop sums every solution's wall time even though days don't depend on each
+//! other. This dispatches each [`Solution`] to a pool of workers instead, reading its input and
+//! calling `wrapper` off the main thread.
+use crate::{fetch, input_path, Solution};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The outcome of computing a single [`Solution`], independent of how it was computed.
+pub struct Computed {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+    pub answers: Option<(String, String)>,
+    pub duration: Duration,
+}
+
+/// Computes every solution across a pool of worker threads, returning results in the original
+/// order plus both the summed per-solution CPU time and the real wall-clock time spent.
+///
+/// Defaults to one worker per available core. Pass `--jobs=N` to cap this: solutions such as
+/// `year2015::day04` already spawn their own threads inside `parse`, so running many of them
+/// concurrently on top of a full-width outer pool would oversubscribe the machine's cores.
+pub fn run(solutions: &[Solution], jobs: Option<usize>) -> (Vec<Computed>, Duration, Duration) {
+    let workers = jobs.unwrap_or_else(|| thread::available_parallelism().unwrap().get()).max(1);
+    let next = AtomicUsize::new(0);
+    let slots: Vec<_> = (0..solutions.len()).map(|_| Mutex::new(None)).collect();
+
+    let wall_start = Instant::now();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(solution) = solutions.get(index) else { break };
+
+                let path = input_path(solution.year, solution.day);
+                let loaded = fetch::load_input(solution.year, solution.day, &path);
+                let (answers, duration) = match loaded {
+                    Some(data) => {
+                        let start = Instant::now();
+                        let answers = (solution.wrapper)(&data);
+                        (Some(answers), start.elapsed())
+                    }
+                    None => (None, Duration::ZERO),
+                };
+
+                *slots[index].lock().unwrap() = Some(Computed {
+                    year: solution.year,
+                    day: solution.day,
+                    title: solution.title,
+                    answers,
+                    duration,
+                });
+            });
+        }
+    });
+
+    let elapsed = wall_start.elapsed();
+    let computed: Vec<_> =
+        slots.into_iter().map(|slot| slot.into_inner().unwrap().unwrap()).collect();
+    let cpu_time = computed.iter().map(|c| c.duration).sum();
+
+    (computed, cpu_time, elapsed)
+}
@@ -0,0 +1,359 @@
+//! Output formats for the default (single-shot) run mode.
+//!
+//! The run loop in `main` only knows how to hand each result to a [`Reporter`]; the reporter
+//! decides how (and when) to print it. This keeps adding a new `--format` to a matter of adding
+//! a new impl rather than threading `if format == ...` through the loop. Crucially it also keeps
+//! structured output parseable: nothing outside a `Reporter` impl should `println!` while a run
+//! is in progress, or raw text ends up interleaved into the JSON/table output.
+use aoc::util::ansi::*;
+use std::time::Duration;
+
+/// Whether a produced answer matched the expected one on record, for a single part.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Pass,
+    Fail,
+    /// `--check` was requested but no expected answer was on record for this day at all.
+    NoRecord,
+}
+
+impl CheckOutcome {
+    /// Whether this outcome should fail a `--check` run. Only a genuine mismatch against a
+    /// recorded answer counts: a day with nothing recorded yet isn't a regression, so it
+    /// shouldn't gate CI the same way an actual wrong answer does.
+    fn is_mismatch(self) -> bool {
+        self == CheckOutcome::Fail
+    }
+}
+
+/// The `--check` result for both parts of a single day.
+#[derive(Clone, Copy)]
+pub struct Check {
+    pub part1: CheckOutcome,
+    pub part2: CheckOutcome,
+}
+
+impl Check {
+    /// Whether either part is a confirmed mismatch, as opposed to merely unrecorded.
+    pub fn is_mismatch(&self) -> bool {
+        self.part1.is_mismatch() || self.part2.is_mismatch()
+    }
+}
+
+/// One completed or missing solution, as seen by a [`Reporter`].
+pub enum Outcome<'a> {
+    Solved { answer1: &'a str, answer2: &'a str, duration: Duration, check: Option<Check> },
+    Missing,
+}
+
+/// Receives one result per selected solution, in order, then a final summary.
+pub trait Reporter {
+    fn report(&mut self, year: u32, day: u32, title: &str, outcome: Outcome<'_>);
+
+    /// Called once, only when solutions ran across a worker pool, with both the summed
+    /// per-solution CPU time and the real wall-clock time spent.
+    fn timing_summary(&mut self, cpu_time: Duration, wall_time: Duration);
+
+    fn finish(&mut self, solutions: usize, elapsed: Duration);
+}
+
+/// Parses the `--format` flag, defaulting to [`PrettyReporter`] when absent or unrecognized.
+pub fn from_arg(format: Option<&str>) -> Box<dyn Reporter> {
+    match format {
+        Some("table") => Box::new(TableReporter::new()),
+        Some("json") => Box::new(JsonReporter::new()),
+        _ => Box::new(PrettyReporter),
+    }
+}
+
+/// Renders a [`CheckOutcome`] the way each `Reporter` needs it.
+fn check_label(outcome: CheckOutcome) -> &'static str {
+    match outcome {
+        CheckOutcome::Pass => "PASS",
+        CheckOutcome::Fail => "FAIL",
+        CheckOutcome::NoRecord => "NO RECORD",
+    }
+}
+
+/// ANSI-colored, human-readable output. The original and still the default format.
+pub struct PrettyReporter;
+
+impl Reporter for PrettyReporter {
+    fn report(&mut self, year: u32, day: u32, title: &str, outcome: Outcome<'_>) {
+        match outcome {
+            Outcome::Solved { answer1, answer2, duration, check } => {
+                println!("{BOLD}{YELLOW}{year} Day {day:02}: {title}{RESET}");
+                println!("    Part 1: {answer1}");
+                println!("    Part 2: {answer2}");
+                println!("    Duration: {} μs", duration.as_micros());
+
+                if let Some(check) = check {
+                    let color = |outcome: CheckOutcome| match outcome {
+                        CheckOutcome::Pass => GREEN,
+                        CheckOutcome::Fail => RED,
+                        CheckOutcome::NoRecord => YELLOW,
+                    };
+                    println!(
+                        "    Check Part 1: {}{}{RESET}",
+                        color(check.part1),
+                        check_label(check.part1)
+                    );
+                    println!(
+                        "    Check Part 2: {}{}{RESET}",
+                        color(check.part2),
+                        check_label(check.part2)
+                    );
+                }
+            }
+            Outcome::Missing => {
+                let path = crate::input_path(year, day);
+                eprintln!("{BOLD}{RED}{year} Day {day:02}: {title}{RESET}");
+                eprintln!("    Missing input!");
+                eprintln!("    Place input file in {BOLD}{WHITE}{}{RESET}", path.display());
+            }
+        }
+    }
+
+    fn timing_summary(&mut self, cpu_time: Duration, wall_time: Duration) {
+        println!(
+            "{BOLD}{WHITE}CPU time: {} ms (wall clock: {} ms){RESET}",
+            cpu_time.as_millis(),
+            wall_time.as_millis()
+        );
+    }
+
+    fn finish(&mut self, solutions: usize, elapsed: Duration) {
+        println!("{BOLD}{RED}Solutions: {solutions}{RESET}");
+        println!("{BOLD}{GREEN}Elapsed: {} ms{RESET}", elapsed.as_millis());
+    }
+}
+
+/// One row of the `table` format.
+struct Row {
+    year: u32,
+    day: u32,
+    title: String,
+    part1: String,
+    part2: String,
+    duration_us: u128,
+    check: Option<Check>,
+}
+
+/// Fixed-width aligned grid with a header, buffered until `finish` so column widths can be
+/// computed from every row.
+pub struct TableReporter {
+    rows: Vec<Row>,
+    timing: Option<(Duration, Duration)>,
+}
+
+impl TableReporter {
+    fn new() -> Self {
+        TableReporter { rows: Vec::new(), timing: None }
+    }
+
+    /// Width of the Title column: the longest title, but never narrower than its header.
+    fn title_width(&self) -> usize {
+        self.rows.iter().map(|r| r.title.len()).max().unwrap_or(0).max(5)
+    }
+}
+
+impl Reporter for TableReporter {
+    fn report(&mut self, year: u32, day: u32, title: &str, outcome: Outcome<'_>) {
+        let (part1, part2, duration_us, check) = match outcome {
+            Outcome::Solved { answer1, answer2, duration, check } => {
+                (answer1.to_string(), answer2.to_string(), duration.as_micros(), check)
+            }
+            Outcome::Missing => ("-".to_string(), "-".to_string(), 0, None),
+        };
+        let title = title.to_string();
+        self.rows.push(Row { year, day, title, part1, part2, duration_us, check });
+    }
+
+    fn timing_summary(&mut self, cpu_time: Duration, wall_time: Duration) {
+        self.timing = Some((cpu_time, wall_time));
+    }
+
+    fn finish(&mut self, solutions: usize, elapsed: Duration) {
+        let title_width = self.title_width();
+        let part1_width = self.rows.iter().map(|r| r.part1.len()).max().unwrap_or(0).max(6);
+        let part2_width = self.rows.iter().map(|r| r.part2.len()).max().unwrap_or(0).max(6);
+        let checked = self.rows.iter().any(|r| r.check.is_some());
+
+        print!(
+            "{:<4} {:<3} {:<title_width$} {:<part1_width$} {:<part2_width$} {:>14}",
+            "Year", "Day", "Title", "Part 1", "Part 2", "Duration (μs)"
+        );
+        if checked {
+            print!(" {:<9} {:<9}", "Check 1", "Check 2");
+        }
+        println!();
+
+        for row in &self.rows {
+            print!(
+                "{:<4} {:<3} {:<title_width$} {:<part1_width$} {:<part2_width$} {:>14}",
+                row.year, row.day, row.title, row.part1, row.part2, row.duration_us
+            );
+            if checked {
+                let (label1, label2) = match row.check {
+                    Some(check) => (check_label(check.part1), check_label(check.part2)),
+                    None => ("-", "-"),
+                };
+                print!(" {label1:<9} {label2:<9}");
+            }
+            println!();
+        }
+
+        println!("Solutions: {solutions}");
+        println!("Elapsed: {} ms", elapsed.as_millis());
+        if let Some((cpu_time, wall_time)) = self.timing {
+            println!(
+                "CPU time: {} ms (wall clock: {} ms)",
+                cpu_time.as_millis(),
+                wall_time.as_millis()
+            );
+        }
+    }
+}
+
+/// Machine-readable array of per-solution objects plus an aggregate, suitable for piping into a
+/// CI dashboard.
+pub struct JsonReporter {
+    entries: Vec<String>,
+    timing: Option<(Duration, Duration)>,
+}
+
+impl JsonReporter {
+    fn new() -> Self {
+        JsonReporter { entries: Vec::new(), timing: None }
+    }
+}
+
+impl Reporter for JsonReporter {
+    fn report(&mut self, year: u32, day: u32, title: &str, outcome: Outcome<'_>) {
+        let (part1, part2, duration_us, check) = match outcome {
+            Outcome::Solved { answer1, answer2, duration, check } => {
+                (Some(answer1), Some(answer2), duration.as_micros(), check)
+            }
+            Outcome::Missing => (None, None, 0, None),
+        };
+
+        let to_json = |value: Option<&str>| match value {
+            Some(value) => format!("\"{}\"", escape(value)),
+            None => "null".to_string(),
+        };
+
+        let check_json = match check {
+            Some(check) => format!(
+                "{{\"part1\":\"{}\",\"part2\":\"{}\"}}",
+                check_label(check.part1).to_lowercase(),
+                check_label(check.part2).to_lowercase(),
+            ),
+            None => "null".to_string(),
+        };
+
+        self.entries.push(format!(
+            "{{\"year\":{year},\"day\":{day},\"title\":\"{}\",\"part1\":{},\"part2\":{},\
+             \"duration_us\":{duration_us},\"check\":{check_json}}}",
+            escape(title),
+            to_json(part1),
+            to_json(part2),
+        ));
+    }
+
+    fn timing_summary(&mut self, cpu_time: Duration, wall_time: Duration) {
+        self.timing = Some((cpu_time, wall_time));
+    }
+
+    fn finish(&mut self, solutions: usize, elapsed: Duration) {
+        let timing_json = match self.timing {
+            Some((cpu_time, wall_time)) => format!(
+                ",\"cpu_time_ms\":{},\"wall_time_ms\":{}",
+                cpu_time.as_millis(),
+                wall_time.as_millis()
+            ),
+            None => String::new(),
+        };
+
+        println!("[{}]", self.entries.join(","));
+        println!(
+            "{{\"solutions\":{solutions},\"elapsed_ms\":{}{timing_json}}}",
+            elapsed.as_millis()
+        );
+    }
+}
+
+/// Escapes the characters that would otherwise break a JSON string literal.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_record_is_not_a_mismatch() {
+        let check = Check { part1: CheckOutcome::NoRecord, part2: CheckOutcome::NoRecord };
+        assert!(!check.is_mismatch());
+    }
+
+    #[test]
+    fn a_single_failing_part_is_a_mismatch() {
+        let check = Check { part1: CheckOutcome::Pass, part2: CheckOutcome::Fail };
+        assert!(check.is_mismatch());
+    }
+
+    #[test]
+    fn escape_handles_quotes_and_backslashes() {
+        assert_eq!(escape(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(escape(r"C:\temp"), r"C:\\temp");
+        assert_eq!(escape("plain"), "plain");
+    }
+
+    #[test]
+    fn table_reporter_widens_columns_to_the_longest_value() {
+        let mut reporter = TableReporter::new();
+        reporter.report(
+            2015,
+            1,
+            "Short Title",
+            Outcome::Solved {
+                answer1: "1",
+                answer2: "2",
+                duration: Duration::from_micros(1),
+                check: None,
+            },
+        );
+        reporter.report(
+            2015,
+            2,
+            "A Much Longer Title",
+            Outcome::Solved {
+                answer1: "1",
+                answer2: "2",
+                duration: Duration::from_micros(1),
+                check: None,
+            },
+        );
+
+        assert_eq!(reporter.title_width(), "A Much Longer Title".len());
+    }
+
+    #[test]
+    fn table_reporter_enforces_a_minimum_column_width() {
+        let mut reporter = TableReporter::new();
+        reporter.report(
+            2015,
+            1,
+            "X",
+            Outcome::Solved {
+                answer1: "1",
+                answer2: "2",
+                duration: Duration::from_micros(1),
+                check: None,
+            },
+        );
+
+        assert_eq!(reporter.title_width(), 5);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::util::hash::*;
 use crate::util::point::*;
 
+pub const TITLE: &str = "Perfectly Spherical Houses in a Vacuum";
+
 pub fn parse(input: &str) -> Vec<Point> {
     input.trim().as_bytes().iter().map(Point::from_byte).collect()
 }
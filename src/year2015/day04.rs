@@ -22,6 +22,8 @@ use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::thread;
 
+pub const TITLE: &str = "The Ideal Stocking Stuffer";
+
 pub struct Shared {
     prefix: String,
     done: Arc<AtomicBool>,